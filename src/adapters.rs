@@ -0,0 +1,92 @@
+//! Alternate textual representations of a [`crate::Uuid`]
+//!
+//! Each adapter borrows the UUID it was created from and implements both
+//! `Display` (lowercase) and `UpperHex` (uppercase, via the `{:X}` formatter
+//! flag) so callers can target the textual convention a downstream system
+//! expects without hand-rolling byte slicing.
+
+use crate::Uuid;
+use std::fmt;
+
+fn write_hex(f: &mut fmt::Formatter<'_>, bytes: &[u8], upper: bool) -> fmt::Result {
+    for b in bytes {
+        if upper {
+            write!(f, "{:02X}", b)?;
+        } else {
+            write!(f, "{:02x}", b)?;
+        }
+    }
+    Ok(())
+}
+
+/// The 32-character form with no hyphens, e.g. `550e8400e29b41d4a716446655440000`
+pub struct Simple<'a>(pub(crate) &'a Uuid);
+
+impl fmt::Display for Simple<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, self.0.as_bytes(), false)
+    }
+}
+
+impl fmt::UpperHex for Simple<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, self.0.as_bytes(), true)
+    }
+}
+
+/// The standard 8-4-4-4-12 hyphenated form, e.g. `550e8400-e29b-41d4-a716-446655440000`
+pub struct Hyphenated<'a>(pub(crate) &'a Uuid);
+
+impl fmt::Display for Hyphenated<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::UpperHex for Hyphenated<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = self.0.as_bytes();
+        write_hex(f, &bytes[0..4], true)?;
+        write!(f, "-")?;
+        write_hex(f, &bytes[4..6], true)?;
+        write!(f, "-")?;
+        write_hex(f, &bytes[6..8], true)?;
+        write!(f, "-")?;
+        write_hex(f, &bytes[8..10], true)?;
+        write!(f, "-")?;
+        write_hex(f, &bytes[10..16], true)
+    }
+}
+
+/// The URN form, e.g. `urn:uuid:550e8400-e29b-41d4-a716-446655440000`
+pub struct Urn<'a>(pub(crate) &'a Uuid);
+
+impl fmt::Display for Urn<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "urn:uuid:{}", Hyphenated(self.0))
+    }
+}
+
+impl fmt::UpperHex for Urn<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "urn:uuid:")?;
+        fmt::UpperHex::fmt(&Hyphenated(self.0), f)
+    }
+}
+
+/// The braced Microsoft GUID form, e.g. `{550e8400-e29b-41d4-a716-446655440000}`
+pub struct Braced<'a>(pub(crate) &'a Uuid);
+
+impl fmt::Display for Braced<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{{}}}", Hyphenated(self.0))
+    }
+}
+
+impl fmt::UpperHex for Braced<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        fmt::UpperHex::fmt(&Hyphenated(self.0), f)?;
+        write!(f, "}}")
+    }
+}
@@ -0,0 +1,64 @@
+//! Chainable construction of UUIDs with arbitrary version and variant bits
+//!
+//! Mirrors the reference `uuid` crate's `builder` module: wrap raw bytes,
+//! stamp the version/variant fields the same way `Uuid::new_v4` does, then
+//! `.build()` into a [`crate::Uuid`]. This gives callers a safe construction
+//! path for versions this crate doesn't generate directly.
+
+use crate::Uuid;
+
+/// Builds a [`Uuid`] from raw bytes, with chainable setters for the
+/// version and variant fields
+pub struct Builder {
+    bytes: [u8; 16],
+}
+
+impl Builder {
+    /// Starts building a UUID from the given 16 raw bytes
+    pub fn from_bytes(bytes: [u8; 16]) -> Self {
+        Builder { bytes }
+    }
+
+    /// Sets the 4-bit version field (upper nibble of byte 6)
+    pub fn with_version(mut self, version: u8) -> Self {
+        self.bytes[6] = (self.bytes[6] & 0x0f) | (version << 4);
+        self
+    }
+
+    /// Sets the variant bits (upper 2 bits of byte 8) to the RFC 4122 variant (`0b10`)
+    pub fn with_variant(mut self) -> Self {
+        self.bytes[8] = (self.bytes[8] & 0x3f) | 0x80;
+        self
+    }
+
+    /// Finishes building, producing the resulting UUID
+    pub fn build(self) -> Uuid {
+        Uuid::from_bytes(self.bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_stamps_version_and_variant() {
+        let uuid = Builder::from_bytes([0xab; 16])
+            .with_version(4)
+            .with_variant()
+            .build();
+
+        assert_eq!(uuid.version(), 4);
+        assert_eq!(uuid.variant(), 2);
+    }
+
+    #[test]
+    fn test_builder_preserves_other_bits() {
+        let uuid = Builder::from_bytes([0x11; 16])
+            .with_version(5)
+            .with_variant()
+            .build();
+
+        assert_eq!(uuid.as_bytes()[0], 0x11, "Untouched bytes should be unchanged");
+    }
+}
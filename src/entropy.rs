@@ -0,0 +1,123 @@
+//! Platform-specific entropy collection backends
+//!
+//! `fill_random_bytes` needs a cryptographically secure random source on every
+//! platform the crate supports, without pulling in an external dependency:
+//! - Windows: `BCryptGenRandom`
+//! - Linux: the `getrandom(2)` syscall, falling back to `/dev/urandom`
+//! - Other Unix: `/dev/urandom`
+
+use crate::UuidError;
+
+#[cfg(target_os = "windows")]
+pub(crate) fn fill_random_bytes(buffer: &mut [u8]) -> Result<(), UuidError> {
+    windows::fill_random_bytes(buffer)
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn fill_random_bytes(buffer: &mut [u8]) -> Result<(), UuidError> {
+    linux::fill_random_bytes(buffer)
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+pub(crate) fn fill_random_bytes(buffer: &mut [u8]) -> Result<(), UuidError> {
+    unix::fill_random_bytes(buffer)
+}
+
+/// `BCryptGenRandom`-backed entropy source for Windows
+#[cfg(target_os = "windows")]
+mod windows {
+    use crate::UuidError;
+
+    #[link(name = "bcrypt")]
+    extern "system" {
+        fn BCryptGenRandom(
+            h_algorithm: *mut core::ffi::c_void,
+            pb_buffer: *mut u8,
+            cb_buffer: u32,
+            dw_flags: u32,
+        ) -> i32;
+    }
+
+    /// Use the system-preferred RNG instead of requiring an algorithm handle
+    const BCRYPT_USE_SYSTEM_PREFERRED_RNG: u32 = 0x0000_0002;
+
+    pub(crate) fn fill_random_bytes(buffer: &mut [u8]) -> Result<(), UuidError> {
+        let status = unsafe {
+            BCryptGenRandom(
+                core::ptr::null_mut(),
+                buffer.as_mut_ptr(),
+                buffer.len() as u32,
+                BCRYPT_USE_SYSTEM_PREFERRED_RNG,
+            )
+        };
+
+        if status == 0 {
+            Ok(())
+        } else {
+            Err(UuidError::EntropyError(format!(
+                "BCryptGenRandom failed with NTSTATUS 0x{:08x}",
+                status
+            )))
+        }
+    }
+}
+
+/// `getrandom(2)`-backed entropy source for Linux, falling back to `/dev/urandom`
+#[cfg(target_os = "linux")]
+mod linux {
+    use super::unix;
+    use crate::UuidError;
+
+    #[cfg(target_arch = "x86_64")]
+    const SYS_GETRANDOM: i64 = 318;
+    #[cfg(target_arch = "x86")]
+    const SYS_GETRANDOM: i64 = 355;
+    #[cfg(target_arch = "aarch64")]
+    const SYS_GETRANDOM: i64 = 278;
+    #[cfg(target_arch = "arm")]
+    const SYS_GETRANDOM: i64 = 384;
+
+    extern "C" {
+        fn syscall(number: i64, ...) -> i64;
+    }
+
+    pub(crate) fn fill_random_bytes(buffer: &mut [u8]) -> Result<(), UuidError> {
+        let mut filled = 0usize;
+
+        while filled < buffer.len() {
+            let remaining = &mut buffer[filled..];
+            let result =
+                unsafe { syscall(SYS_GETRANDOM, remaining.as_mut_ptr(), remaining.len(), 0u32) };
+
+            if result < 0 {
+                // getrandom(2) unavailable (pre-3.17 kernel, seccomp filter,
+                // etc) -- fall back to reading /dev/urandom directly.
+                return unix::fill_random_bytes(&mut buffer[filled..]);
+            }
+
+            filled += result as usize;
+        }
+
+        Ok(())
+    }
+}
+
+/// `/dev/urandom`-backed entropy source shared by all Unix-like targets
+#[cfg(unix)]
+mod unix {
+    use crate::UuidError;
+    use std::fs::File;
+    use std::io::Read;
+
+    pub(crate) fn fill_random_bytes(buffer: &mut [u8]) -> Result<(), UuidError> {
+        // /dev/urandom is preferred over /dev/random as it doesn't block
+        // and provides cryptographically secure pseudorandom data
+        let mut file = File::open("/dev/urandom")
+            .map_err(|e| UuidError::EntropyError(format!("Failed to open /dev/urandom: {}", e)))?;
+
+        file.read_exact(buffer)
+            .map_err(|e| UuidError::EntropyError(format!("Failed to read random bytes: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -15,20 +15,20 @@
 //! #include <stdint.h>
 //! 
 //! int32_t uuid_generate_v4(uint8_t* uuid_bytes);
-//! int32_t uuid_to_string(const uint8_t* uuid_bytes, char* uuid_string, size_t buffer_size);
+//! int32_t uuid_to_string(const uint8_t* uuid_bytes, char* uuid_string, size_t buffer_size, int32_t format);
 //! */
 //! import "C"
 //! import "unsafe"
-//! 
+//!
 //! func GenerateUUID() (string, error) {
 //!     var uuidBytes [16]C.uint8_t
 //!     result := C.uuid_generate_v4(&uuidBytes[0])
 //!     if result != 0 {
 //!         return "", fmt.Errorf("failed to generate UUID: error code %d", result)
 //!     }
-//!     
+//!
 //!     var buffer [37]C.char // 36 chars + null terminator
-//!     result = C.uuid_to_string(&uuidBytes[0], &buffer[0], 37)
+//!     result = C.uuid_to_string(&uuidBytes[0], &buffer[0], 37, 1 /* Hyphenated */)
 //!     if result != 0 {
 //!         return "", fmt.Errorf("failed to convert UUID to string: error code %d", result)
 //!     }
@@ -37,7 +37,7 @@
 //! }
 //! ```
 
-use crate::{Uuid, UuidError};
+use crate::{sequencing, Uuid, UuidError};
 use std::os::raw::{c_char, c_int};
 use std::ptr;
 use std::slice;
@@ -57,6 +57,19 @@ pub enum UuidFfiError {
     UnknownError = 99,
 }
 
+/// Textual formats available to `uuid_to_string`
+#[repr(C)]
+pub enum UuidStringFormat {
+    /// 32 hex characters with no hyphens
+    Simple = 0,
+    /// The standard 8-4-4-4-12 hyphenated form
+    Hyphenated = 1,
+    /// Prefixed with `urn:uuid:`
+    Urn = 2,
+    /// Wrapped in `{}`
+    Braced = 3,
+}
+
 /// Generates a new UUID v4 and writes the bytes to the provided buffer
 ///
 /// # Parameters
@@ -88,17 +101,264 @@ pub extern "C" fn uuid_generate_v4(uuid_bytes: *mut u8) -> c_int {
     }
 }
 
+/// Generates a new time-ordered UUID v7 and writes the bytes to the provided buffer
+///
+/// UUID v7 UUIDs sort lexicographically by creation time, which makes them
+/// far friendlier to B-tree database indexes than random v4 UUIDs. A
+/// process-global counter keeps UUIDs generated within the same millisecond
+/// monotonically increasing.
+///
+/// # Parameters
+/// - `uuid_bytes`: Pointer to a 16-byte buffer where the UUID will be written
+///
+/// # Returns
+/// - `0` (Success) if UUID was generated successfully
+/// - `1` (EntropyFailure) if random data generation failed or the system
+///   clock is before the Unix epoch
+/// - `2` (InvalidParameter) if uuid_bytes is null
+///
+/// # Safety
+/// The caller must ensure that `uuid_bytes` points to a valid 16-byte buffer.
+#[no_mangle]
+pub extern "C" fn uuid_generate_v7(uuid_bytes: *mut u8) -> c_int {
+    if uuid_bytes.is_null() {
+        return UuidFfiError::InvalidParameter as c_int;
+    }
+
+    let bytes = match sequencing::generate_v7() {
+        Ok(bytes) => bytes,
+        Err(UuidError::EntropyError(_)) => return UuidFfiError::EntropyFailure as c_int,
+        Err(_) => return UuidFfiError::UnknownError as c_int,
+    };
+
+    unsafe {
+        let buffer = slice::from_raw_parts_mut(uuid_bytes, 16);
+        buffer.copy_from_slice(&bytes);
+    }
+
+    UuidFfiError::Success as c_int
+}
+
+/// Generates a new time-based UUID v1 and writes the bytes to the provided buffer
+///
+/// Embeds the current Gregorian-epoch timestamp and the caller-supplied
+/// `node_id` (e.g. a MAC address). A process-global clock sequence is
+/// maintained so that two calls landing in the same or a backwards-moving
+/// tick still produce unique UUIDs.
+///
+/// # Parameters
+/// - `node_id`: Pointer to a 6-byte node identifier
+/// - `out`: Pointer to a 16-byte buffer where the UUID will be written
+///
+/// # Returns
+/// - `0` (Success) if UUID was generated successfully
+/// - `1` (EntropyFailure) if random data generation failed or the system
+///   clock is before the Unix epoch
+/// - `2` (InvalidParameter) if `node_id` or `out` is null
+///
+/// # Safety
+/// The caller must ensure that `node_id` points to 6 valid bytes and `out`
+/// points to a valid 16-byte buffer.
+#[no_mangle]
+pub extern "C" fn uuid_generate_v1(node_id: *const u8, out: *mut u8) -> c_int {
+    if node_id.is_null() || out.is_null() {
+        return UuidFfiError::InvalidParameter as c_int;
+    }
+
+    let mut node_buf = [0u8; 6];
+    unsafe {
+        let node_slice = slice::from_raw_parts(node_id, 6);
+        node_buf.copy_from_slice(node_slice);
+    }
+
+    let bytes = match sequencing::generate_v1(node_buf) {
+        Ok(bytes) => bytes,
+        Err(UuidError::EntropyError(_)) => return UuidFfiError::EntropyFailure as c_int,
+        Err(_) => return UuidFfiError::UnknownError as c_int,
+    };
+
+    unsafe {
+        let buffer = slice::from_raw_parts_mut(out, 16);
+        buffer.copy_from_slice(&bytes);
+    }
+
+    UuidFfiError::Success as c_int
+}
+
+/// The DNS namespace (RFC 4122/9562), for use with `uuid_generate_v5`/`uuid_generate_v3`
+#[no_mangle]
+pub static UUID_NAMESPACE_DNS: [u8; 16] = [
+    0x6b, 0xa7, 0xb8, 0x10, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8,
+];
+
+/// The URL namespace (RFC 4122/9562), for use with `uuid_generate_v5`/`uuid_generate_v3`
+#[no_mangle]
+pub static UUID_NAMESPACE_URL: [u8; 16] = [
+    0x6b, 0xa7, 0xb8, 0x11, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8,
+];
+
+/// The ISO OID namespace (RFC 4122/9562), for use with `uuid_generate_v5`/`uuid_generate_v3`
+#[no_mangle]
+pub static UUID_NAMESPACE_OID: [u8; 16] = [
+    0x6b, 0xa7, 0xb8, 0x12, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8,
+];
+
+/// The X.500 DN namespace (RFC 4122/9562), for use with `uuid_generate_v5`/`uuid_generate_v3`
+#[no_mangle]
+pub static UUID_NAMESPACE_X500: [u8; 16] = [
+    0x6b, 0xa7, 0xb8, 0x14, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8,
+];
+
+/// Generates a name-based UUID v5 (SHA-1) from a namespace and name
+///
+/// # Parameters
+/// - `namespace`: Pointer to a 16-byte namespace UUID (see the `UUID_NAMESPACE_*` statics)
+/// - `name`: Pointer to the name bytes (may be null only if `name_len` is 0)
+/// - `name_len`: Length of the name in bytes
+/// - `out`: Pointer to a 16-byte buffer where the UUID will be written
+///
+/// # Returns
+/// - `0` (Success) if UUID was generated successfully
+/// - `2` (InvalidParameter) if `namespace`/`out` is null, or `name` is null with a non-zero `name_len`
+///
+/// # Safety
+/// The caller must ensure that `namespace` and `out` point to valid 16-byte
+/// buffers and that `name` points to at least `name_len` valid bytes.
+#[no_mangle]
+pub extern "C" fn uuid_generate_v5(
+    namespace: *const u8,
+    name: *const u8,
+    name_len: usize,
+    out: *mut u8,
+) -> c_int {
+    generate_name_based(namespace, name, name_len, out, Uuid::new_v5)
+}
+
+/// Generates a name-based UUID v3 (MD5) from a namespace and name
+///
+/// # Parameters
+/// - `namespace`: Pointer to a 16-byte namespace UUID (see the `UUID_NAMESPACE_*` statics)
+/// - `name`: Pointer to the name bytes (may be null only if `name_len` is 0)
+/// - `name_len`: Length of the name in bytes
+/// - `out`: Pointer to a 16-byte buffer where the UUID will be written
+///
+/// # Returns
+/// - `0` (Success) if UUID was generated successfully
+/// - `2` (InvalidParameter) if `namespace`/`out` is null, or `name` is null with a non-zero `name_len`
+///
+/// # Safety
+/// The caller must ensure that `namespace` and `out` point to valid 16-byte
+/// buffers and that `name` points to at least `name_len` valid bytes.
+#[no_mangle]
+pub extern "C" fn uuid_generate_v3(
+    namespace: *const u8,
+    name: *const u8,
+    name_len: usize,
+    out: *mut u8,
+) -> c_int {
+    generate_name_based(namespace, name, name_len, out, Uuid::new_v3)
+}
+
+/// Shared implementation backing `uuid_generate_v5` and `uuid_generate_v3`
+fn generate_name_based(
+    namespace: *const u8,
+    name: *const u8,
+    name_len: usize,
+    out: *mut u8,
+    generate: impl Fn(&Uuid, &[u8]) -> Uuid,
+) -> c_int {
+    if namespace.is_null() || out.is_null() || (name.is_null() && name_len != 0) {
+        return UuidFfiError::InvalidParameter as c_int;
+    }
+
+    unsafe {
+        let namespace_slice = slice::from_raw_parts(namespace, 16);
+        let mut namespace_bytes = [0u8; 16];
+        namespace_bytes.copy_from_slice(namespace_slice);
+        let namespace_uuid = Uuid::from_bytes(namespace_bytes);
+
+        let name_slice = if name_len == 0 {
+            &[][..]
+        } else {
+            slice::from_raw_parts(name, name_len)
+        };
+
+        let uuid = generate(&namespace_uuid, name_slice);
+
+        let buffer = slice::from_raw_parts_mut(out, 16);
+        buffer.copy_from_slice(uuid.as_bytes());
+    }
+
+    UuidFfiError::Success as c_int
+}
+
+/// Generates `count` UUID v4s into a single contiguous buffer
+///
+/// Crossing the FFI boundary once per UUID dominates the cost when
+/// generating thousands of IDs. This draws all random bytes for the whole
+/// batch in one fill, then stamps the version and variant bits into each
+/// 16-byte slot.
+///
+/// # Parameters
+/// - `out_bytes`: Pointer to a buffer of at least `count * 16` bytes
+/// - `count`: Number of UUIDs to generate
+///
+/// # Returns
+/// - `0` (Success) if all UUIDs were generated successfully
+/// - `1` (EntropyFailure) if random data generation failed
+/// - `2` (InvalidParameter) if `out_bytes` is null or `count * 16` overflows `usize`
+///
+/// # Safety
+/// The caller must ensure that `out_bytes` points to a valid buffer of at
+/// least `count * 16` bytes.
+#[no_mangle]
+pub extern "C" fn uuid_generate_v4_batch(out_bytes: *mut u8, count: usize) -> c_int {
+    if out_bytes.is_null() {
+        return UuidFfiError::InvalidParameter as c_int;
+    }
+
+    let total_bytes = match count.checked_mul(16) {
+        Some(n) => n,
+        None => return UuidFfiError::InvalidParameter as c_int,
+    };
+
+    if total_bytes == 0 {
+        return UuidFfiError::Success as c_int;
+    }
+
+    unsafe {
+        let buffer = slice::from_raw_parts_mut(out_bytes, total_bytes);
+
+        if let Err(e) = crate::entropy::fill_random_bytes(buffer) {
+            return match e {
+                UuidError::EntropyError(_) => UuidFfiError::EntropyFailure as c_int,
+                _ => UuidFfiError::UnknownError as c_int,
+            };
+        }
+
+        for slot in buffer.chunks_exact_mut(16) {
+            slot[6] = (slot[6] & 0x0f) | 0x40;
+            slot[8] = (slot[8] & 0x3f) | 0x80;
+        }
+    }
+
+    UuidFfiError::Success as c_int
+}
+
 /// Converts UUID bytes to a null-terminated string representation
 ///
 /// # Parameters
 /// - `uuid_bytes`: Pointer to a 16-byte UUID
 /// - `uuid_string`: Pointer to a buffer where the string will be written
-/// - `buffer_size`: Size of the string buffer (must be at least 37 bytes)
+/// - `buffer_size`: Size of the string buffer; the minimum required depends
+///   on `format` (33 / 37 / 46 / 39 bytes, including the null terminator, for
+///   Simple / Hyphenated / Urn / Braced respectively)
+/// - `format`: One of the [`UuidStringFormat`] variants
 ///
 /// # Returns
 /// - `0` (Success) if conversion was successful
-/// - `2` (InvalidParameter) if any pointer is null
-/// - `3` (BufferTooSmall) if buffer_size < 37
+/// - `2` (InvalidParameter) if any pointer is null or `format` is unrecognized
+/// - `3` (BufferTooSmall) if `buffer_size` is too small for the requested format
 ///
 /// # Safety
 /// The caller must ensure that:
@@ -110,12 +370,21 @@ pub extern "C" fn uuid_to_string(
     uuid_bytes: *const u8,
     uuid_string: *mut c_char,
     buffer_size: usize,
+    format: c_int,
 ) -> c_int {
     if uuid_bytes.is_null() || uuid_string.is_null() {
         return UuidFfiError::InvalidParameter as c_int;
     }
 
-    if buffer_size < 37 {
+    let min_size = match format {
+        0 => 32 + 1,
+        1 => 36 + 1,
+        2 => 45 + 1,
+        3 => 38 + 1,
+        _ => return UuidFfiError::InvalidParameter as c_int,
+    };
+
+    if buffer_size < min_size {
         return UuidFfiError::BufferTooSmall as c_int;
     }
 
@@ -123,10 +392,15 @@ pub extern "C" fn uuid_to_string(
         let uuid_bytes_slice = slice::from_raw_parts(uuid_bytes, 16);
         let mut uuid_array = [0u8; 16];
         uuid_array.copy_from_slice(uuid_bytes_slice);
-        
+
         let uuid = Uuid::from_bytes(uuid_array);
-        let uuid_str = format!("{}", uuid);
-        
+        let uuid_str = match format {
+            0 => uuid.simple().to_string(),
+            1 => uuid.hyphenated().to_string(),
+            2 => uuid.urn().to_string(),
+            _ => uuid.braced().to_string(),
+        };
+
         let uuid_cstring = match std::ffi::CString::new(uuid_str) {
             Ok(s) => s,
             Err(_) => return UuidFfiError::UnknownError as c_int,
@@ -147,6 +421,48 @@ pub extern "C" fn uuid_to_string(
     UuidFfiError::Success as c_int
 }
 
+/// Parses a textual UUID into its canonical 16-byte representation
+///
+/// Accepts, case-insensitively, the hyphenated form, the simple/unhyphenated
+/// form, the URN form (`urn:uuid:...`), and the braced Microsoft GUID form
+/// (`{...}`).
+///
+/// # Parameters
+/// - `input`: Pointer to a null-terminated C string containing the UUID text
+/// - `out_bytes`: Pointer to a 16-byte buffer where the parsed bytes will be written
+///
+/// # Returns
+/// - `0` (Success) if the input was parsed successfully
+/// - `2` (InvalidParameter) if any pointer is null, the input is not valid
+///   UTF-8, or the text is not a well-formed UUID
+///
+/// # Safety
+/// The caller must ensure that `input` points to a valid null-terminated C
+/// string and that `out_bytes` points to a valid 16-byte buffer.
+#[no_mangle]
+pub extern "C" fn uuid_parse_string(input: *const c_char, out_bytes: *mut u8) -> c_int {
+    if input.is_null() || out_bytes.is_null() {
+        return UuidFfiError::InvalidParameter as c_int;
+    }
+
+    let c_str = unsafe { std::ffi::CStr::from_ptr(input) };
+    let s = match c_str.to_str() {
+        Ok(s) => s,
+        Err(_) => return UuidFfiError::InvalidParameter as c_int,
+    };
+
+    match Uuid::parse_str(s) {
+        Ok(uuid) => {
+            unsafe {
+                let buffer = slice::from_raw_parts_mut(out_bytes, 16);
+                buffer.copy_from_slice(uuid.as_bytes());
+            }
+            UuidFfiError::Success as c_int
+        }
+        Err(_) => UuidFfiError::InvalidParameter as c_int,
+    }
+}
+
 /// Validates UUID bytes and returns version and variant information
 ///
 /// # Parameters
@@ -225,6 +541,72 @@ pub extern "C" fn uuid_compare(
     UuidFfiError::Success as c_int
 }
 
+/// Converts canonical big-endian UUID bytes to the little-endian GUID field
+/// layout used by Windows/.NET/COM
+///
+/// # Parameters
+/// - `uuid_bytes`: Pointer to a 16-byte big-endian UUID
+/// - `guid_bytes`: Pointer to a 16-byte buffer where the GUID-ordered bytes will be written
+///
+/// # Returns
+/// - `0` (Success) if the conversion was successful
+/// - `2` (InvalidParameter) if any pointer is null
+///
+/// # Safety
+/// The caller must ensure that `uuid_bytes` and `guid_bytes` both point to
+/// valid 16-byte buffers.
+#[no_mangle]
+pub extern "C" fn uuid_to_guid_bytes(uuid_bytes: *const u8, guid_bytes: *mut u8) -> c_int {
+    if uuid_bytes.is_null() || guid_bytes.is_null() {
+        return UuidFfiError::InvalidParameter as c_int;
+    }
+
+    unsafe {
+        let uuid_slice = slice::from_raw_parts(uuid_bytes, 16);
+        let mut uuid_array = [0u8; 16];
+        uuid_array.copy_from_slice(uuid_slice);
+
+        let uuid = Uuid::from_bytes(uuid_array);
+        let guid_slice = slice::from_raw_parts_mut(guid_bytes, 16);
+        guid_slice.copy_from_slice(&uuid.to_bytes_le());
+    }
+
+    UuidFfiError::Success as c_int
+}
+
+/// Converts little-endian GUID-ordered bytes (Windows/.NET/COM) to the
+/// canonical big-endian UUID representation
+///
+/// # Parameters
+/// - `guid_bytes`: Pointer to a 16-byte GUID-ordered buffer
+/// - `uuid_bytes`: Pointer to a 16-byte buffer where the big-endian UUID will be written
+///
+/// # Returns
+/// - `0` (Success) if the conversion was successful
+/// - `2` (InvalidParameter) if any pointer is null
+///
+/// # Safety
+/// The caller must ensure that `guid_bytes` and `uuid_bytes` both point to
+/// valid 16-byte buffers.
+#[no_mangle]
+pub extern "C" fn uuid_from_guid_bytes(guid_bytes: *const u8, uuid_bytes: *mut u8) -> c_int {
+    if guid_bytes.is_null() || uuid_bytes.is_null() {
+        return UuidFfiError::InvalidParameter as c_int;
+    }
+
+    unsafe {
+        let guid_slice = slice::from_raw_parts(guid_bytes, 16);
+        let mut guid_array = [0u8; 16];
+        guid_array.copy_from_slice(guid_slice);
+
+        let uuid = Uuid::from_bytes_le(guid_array);
+        let uuid_slice = slice::from_raw_parts_mut(uuid_bytes, 16);
+        uuid_slice.copy_from_slice(uuid.as_bytes());
+    }
+
+    UuidFfiError::Success as c_int
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -250,6 +632,119 @@ mod tests {
         assert_eq!(result, UuidFfiError::InvalidParameter as c_int);
     }
 
+    #[test]
+    fn test_ffi_uuid_generate_v7() {
+        let mut uuid_bytes = [0u8; 16];
+        let result = uuid_generate_v7(uuid_bytes.as_mut_ptr());
+
+        assert_eq!(result, UuidFfiError::Success as c_int);
+
+        let uuid = Uuid::from_bytes(uuid_bytes);
+        assert_eq!(uuid.version(), 7);
+        assert_eq!(uuid.variant(), 2);
+    }
+
+    #[test]
+    fn test_ffi_uuid_generate_v7_null_pointer() {
+        let result = uuid_generate_v7(ptr::null_mut());
+        assert_eq!(result, UuidFfiError::InvalidParameter as c_int);
+    }
+
+    #[test]
+    fn test_ffi_uuid_generate_v7_monotonic() {
+        let mut first_bytes = [0u8; 16];
+        let mut second_bytes = [0u8; 16];
+
+        uuid_generate_v7(first_bytes.as_mut_ptr());
+        uuid_generate_v7(second_bytes.as_mut_ptr());
+
+        let first = Uuid::from_bytes(first_bytes);
+        let second = Uuid::from_bytes(second_bytes);
+
+        assert!(
+            first.get_timestamp().unwrap() <= second.get_timestamp().unwrap(),
+            "Sequential v7 UUIDs should have non-decreasing timestamps"
+        );
+    }
+
+    #[test]
+    fn test_ffi_uuid_generate_v1() {
+        let node_id = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+        let mut out = [0u8; 16];
+
+        let result = uuid_generate_v1(node_id.as_ptr(), out.as_mut_ptr());
+
+        assert_eq!(result, UuidFfiError::Success as c_int);
+
+        let uuid = Uuid::from_bytes(out);
+        assert_eq!(uuid.version(), 1);
+        assert_eq!(uuid.variant(), 2);
+        assert_eq!(&out[10..16], &node_id);
+    }
+
+    #[test]
+    fn test_ffi_uuid_generate_v1_null_pointer() {
+        let mut out = [0u8; 16];
+        let result = uuid_generate_v1(ptr::null(), out.as_mut_ptr());
+        assert_eq!(result, UuidFfiError::InvalidParameter as c_int);
+    }
+
+    #[test]
+    fn test_ffi_uuid_generate_v5() {
+        let mut out = [0u8; 16];
+        let name = b"example.com";
+
+        let result = uuid_generate_v5(
+            UUID_NAMESPACE_DNS.as_ptr(),
+            name.as_ptr(),
+            name.len(),
+            out.as_mut_ptr(),
+        );
+
+        assert_eq!(result, UuidFfiError::Success as c_int);
+
+        let uuid = Uuid::from_bytes(out);
+        assert_eq!(uuid.version(), 5);
+        assert_eq!(uuid.variant(), 2);
+        assert_eq!(uuid.to_string(), "cfbff0d1-9375-5685-968c-48ce8b15ae17");
+    }
+
+    #[test]
+    fn test_ffi_uuid_generate_v3() {
+        let mut out = [0u8; 16];
+        let name = b"example.com";
+
+        let result = uuid_generate_v3(
+            UUID_NAMESPACE_DNS.as_ptr(),
+            name.as_ptr(),
+            name.len(),
+            out.as_mut_ptr(),
+        );
+
+        assert_eq!(result, UuidFfiError::Success as c_int);
+
+        let uuid = Uuid::from_bytes(out);
+        assert_eq!(uuid.version(), 3);
+        assert_eq!(uuid.variant(), 2);
+    }
+
+    #[test]
+    fn test_ffi_uuid_generate_v5_null_namespace() {
+        let mut out = [0u8; 16];
+        let name = b"example.com";
+
+        let result = uuid_generate_v5(ptr::null(), name.as_ptr(), name.len(), out.as_mut_ptr());
+        assert_eq!(result, UuidFfiError::InvalidParameter as c_int);
+    }
+
+    #[test]
+    fn test_ffi_uuid_generate_v5_empty_name() {
+        let mut out = [0u8; 16];
+
+        let result = uuid_generate_v5(UUID_NAMESPACE_DNS.as_ptr(), ptr::null(), 0, out.as_mut_ptr());
+        assert_eq!(result, UuidFfiError::Success as c_int);
+    }
+
     #[test]
     fn test_ffi_uuid_to_string() {
         let mut uuid_bytes = [0u8; 16];
@@ -261,10 +756,11 @@ mod tests {
             uuid_bytes.as_ptr(),
             buffer.as_mut_ptr(),
             buffer.len(),
+            UuidStringFormat::Hyphenated as c_int,
         );
-        
+
         assert_eq!(result, UuidFfiError::Success as c_int);
-        
+
         let c_str = unsafe { CStr::from_ptr(buffer.as_ptr()) };
         let uuid_str = c_str.to_str().unwrap();
         assert_eq!(uuid_str.len(), 36);
@@ -278,16 +774,130 @@ mod tests {
     fn test_ffi_uuid_to_string_buffer_too_small() {
         let uuid_bytes = [0u8; 16];
         let mut buffer = [0i8; 36];
-        
+
         let result = uuid_to_string(
             uuid_bytes.as_ptr(),
             buffer.as_mut_ptr(),
             buffer.len(),
+            UuidStringFormat::Hyphenated as c_int,
         );
-        
+
         assert_eq!(result, UuidFfiError::BufferTooSmall as c_int);
     }
 
+    #[test]
+    fn test_ffi_uuid_to_string_simple_format() {
+        let uuid_bytes = [0u8; 16];
+        let mut buffer = [0i8; 33];
+
+        let result = uuid_to_string(
+            uuid_bytes.as_ptr(),
+            buffer.as_mut_ptr(),
+            buffer.len(),
+            UuidStringFormat::Simple as c_int,
+        );
+
+        assert_eq!(result, UuidFfiError::Success as c_int);
+        let c_str = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+        assert_eq!(c_str.to_str().unwrap().len(), 32);
+    }
+
+    #[test]
+    fn test_ffi_uuid_to_string_urn_format() {
+        let uuid_bytes = [0u8; 16];
+        let mut buffer = [0i8; 46];
+
+        let result = uuid_to_string(
+            uuid_bytes.as_ptr(),
+            buffer.as_mut_ptr(),
+            buffer.len(),
+            UuidStringFormat::Urn as c_int,
+        );
+
+        assert_eq!(result, UuidFfiError::Success as c_int);
+        let c_str = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+        assert!(c_str.to_str().unwrap().starts_with("urn:uuid:"));
+    }
+
+    #[test]
+    fn test_ffi_uuid_to_string_braced_format() {
+        let uuid_bytes = [0u8; 16];
+        let mut buffer = [0i8; 39];
+
+        let result = uuid_to_string(
+            uuid_bytes.as_ptr(),
+            buffer.as_mut_ptr(),
+            buffer.len(),
+            UuidStringFormat::Braced as c_int,
+        );
+
+        assert_eq!(result, UuidFfiError::Success as c_int);
+        let c_str = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+        let s = c_str.to_str().unwrap();
+        assert!(s.starts_with('{') && s.ends_with('}'));
+    }
+
+    #[test]
+    fn test_ffi_uuid_to_string_invalid_format() {
+        let uuid_bytes = [0u8; 16];
+        let mut buffer = [0i8; 64];
+
+        let result = uuid_to_string(uuid_bytes.as_ptr(), buffer.as_mut_ptr(), buffer.len(), 99);
+        assert_eq!(result, UuidFfiError::InvalidParameter as c_int);
+    }
+
+    #[test]
+    fn test_ffi_uuid_parse_string() {
+        let input = std::ffi::CString::new("550e8400-e29b-41d4-a716-446655440000").unwrap();
+        let mut out_bytes = [0u8; 16];
+
+        let result = uuid_parse_string(input.as_ptr(), out_bytes.as_mut_ptr());
+
+        assert_eq!(result, UuidFfiError::Success as c_int);
+        assert_eq!(
+            Uuid::from_bytes(out_bytes).to_string(),
+            "550e8400-e29b-41d4-a716-446655440000"
+        );
+    }
+
+    #[test]
+    fn test_ffi_uuid_parse_string_other_forms() {
+        let cases = [
+            "550e8400e29b41d4a716446655440000",
+            "urn:uuid:550e8400-e29b-41d4-a716-446655440000",
+            "{550E8400-E29B-41D4-A716-446655440000}",
+        ];
+
+        for case in cases {
+            let input = std::ffi::CString::new(case).unwrap();
+            let mut out_bytes = [0u8; 16];
+
+            let result = uuid_parse_string(input.as_ptr(), out_bytes.as_mut_ptr());
+
+            assert_eq!(result, UuidFfiError::Success as c_int, "Failed to parse {}", case);
+            assert_eq!(
+                Uuid::from_bytes(out_bytes).to_string(),
+                "550e8400-e29b-41d4-a716-446655440000"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ffi_uuid_parse_string_invalid() {
+        let input = std::ffi::CString::new("not-a-uuid").unwrap();
+        let mut out_bytes = [0u8; 16];
+
+        let result = uuid_parse_string(input.as_ptr(), out_bytes.as_mut_ptr());
+        assert_eq!(result, UuidFfiError::InvalidParameter as c_int);
+    }
+
+    #[test]
+    fn test_ffi_uuid_parse_string_null_pointer() {
+        let mut out_bytes = [0u8; 16];
+        let result = uuid_parse_string(ptr::null(), out_bytes.as_mut_ptr());
+        assert_eq!(result, UuidFfiError::InvalidParameter as c_int);
+    }
+
     #[test]
     fn test_ffi_uuid_get_info() {
         let mut uuid_bytes = [0u8; 16];
@@ -335,4 +945,86 @@ mod tests {
         assert_eq!(result, UuidFfiError::Success as c_int);
         assert_eq!(are_equal, 1);
     }
+
+    #[test]
+    fn test_ffi_uuid_generate_v4_batch() {
+        let mut buffer = [0u8; 16 * 5];
+        let result = uuid_generate_v4_batch(buffer.as_mut_ptr(), 5);
+        assert_eq!(result, UuidFfiError::Success as c_int);
+
+        for slot in buffer.chunks_exact(16) {
+            assert_eq!(slot[6] & 0xf0, 0x40);
+            assert_eq!(slot[8] & 0xc0, 0x80);
+        }
+
+        // The UUIDs in the batch should all be distinct
+        let first: Vec<u8> = buffer[0..16].to_vec();
+        let second: Vec<u8> = buffer[16..32].to_vec();
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_ffi_uuid_generate_v4_batch_zero_count() {
+        let mut buffer = [0u8; 0];
+        let result = uuid_generate_v4_batch(buffer.as_mut_ptr(), 0);
+        assert_eq!(result, UuidFfiError::Success as c_int);
+    }
+
+    #[test]
+    fn test_ffi_uuid_generate_v4_batch_null_pointer() {
+        let result = uuid_generate_v4_batch(ptr::null_mut(), 5);
+        assert_eq!(result, UuidFfiError::InvalidParameter as c_int);
+    }
+
+    #[test]
+    fn test_ffi_uuid_generate_v4_batch_overflow() {
+        let mut buffer = [0u8; 16];
+        let result = uuid_generate_v4_batch(buffer.as_mut_ptr(), usize::MAX);
+        assert_eq!(result, UuidFfiError::InvalidParameter as c_int);
+    }
+
+    #[test]
+    fn test_ffi_uuid_to_guid_bytes_and_back() {
+        let mut uuid_bytes = [0u8; 16];
+        uuid_generate_v4(uuid_bytes.as_mut_ptr());
+
+        let mut guid_bytes = [0u8; 16];
+        let result = uuid_to_guid_bytes(uuid_bytes.as_ptr(), guid_bytes.as_mut_ptr());
+        assert_eq!(result, UuidFfiError::Success as c_int);
+
+        let mut roundtripped = [0u8; 16];
+        let result = uuid_from_guid_bytes(guid_bytes.as_ptr(), roundtripped.as_mut_ptr());
+        assert_eq!(result, UuidFfiError::Success as c_int);
+
+        assert_eq!(uuid_bytes, roundtripped);
+    }
+
+    #[test]
+    fn test_ffi_uuid_to_guid_bytes_swaps_fields() {
+        let uuid_bytes: [u8; 16] = [
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+            0xee, 0xff,
+        ];
+        let mut guid_bytes = [0u8; 16];
+        let result = uuid_to_guid_bytes(uuid_bytes.as_ptr(), guid_bytes.as_mut_ptr());
+        assert_eq!(result, UuidFfiError::Success as c_int);
+
+        assert_eq!(
+            guid_bytes,
+            [
+                0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xaa, 0xbb, 0xcc,
+                0xdd, 0xee, 0xff,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ffi_uuid_to_guid_bytes_null_pointer() {
+        let mut buffer = [0u8; 16];
+        let result = uuid_to_guid_bytes(ptr::null(), buffer.as_mut_ptr());
+        assert_eq!(result, UuidFfiError::InvalidParameter as c_int);
+
+        let result = uuid_from_guid_bytes(ptr::null(), buffer.as_mut_ptr());
+        assert_eq!(result, UuidFfiError::InvalidParameter as c_int);
+    }
 }
@@ -3,16 +3,16 @@
 //! This module provides JNI-compatible functions that can be called from Java.
 //! The functions follow JNI naming conventions and handle JNI types.
 
-use crate::{Uuid, UuidError};
+use crate::{sequencing, Uuid, UuidError};
 use jni::objects::{JByteArray, JClass};
-use jni::sys::{jbyteArray, jint, JNIEnv};
-use std::ptr;
+use jni::sys::{jbyteArray, jint};
+use jni::JNIEnv;
 
 /// JNI function: Generate a new UUID v4
 /// Java signature: private static native int nativeGenerate(byte[] uuidBytes);
 #[no_mangle]
 pub extern "system" fn Java_com_uuidgenerator_UuidGenerator_nativeGenerate(
-    mut env: JNIEnv,
+    env: JNIEnv,
     _class: JClass,
     uuid_bytes: jbyteArray,
 ) -> jint {
@@ -27,9 +27,9 @@ pub extern "system" fn Java_com_uuidgenerator_UuidGenerator_nativeGenerate(
         return 2;
     }
     
-    let uuid = match Uuid::new() {
+    let uuid = match Uuid::new_v4() {
         Ok(uuid) => uuid,
-        Err(UuidError::EntropyError) => return 1,
+        Err(UuidError::EntropyError(_)) => return 1,
         Err(_) => return 99,
     };
     
@@ -43,70 +43,372 @@ pub extern "system" fn Java_com_uuidgenerator_UuidGenerator_nativeGenerate(
     }
 }
 
+/// JNI function: Generate a new time-ordered UUID v7
+/// Java signature: private static native int nativeGenerateV7(byte[] uuidBytes);
+#[no_mangle]
+pub extern "system" fn Java_com_uuidgenerator_UuidGenerator_nativeGenerateV7(
+    env: JNIEnv,
+    _class: JClass,
+    uuid_bytes: jbyteArray,
+) -> jint {
+    let byte_array = unsafe { JByteArray::from_raw(uuid_bytes) };
+
+    let len = match env.get_array_length(&byte_array) {
+        Ok(len) => len,
+        Err(_) => return 2,
+    };
+
+    if len != 16 {
+        return 2;
+    }
+
+    let bytes = match sequencing::generate_v7() {
+        Ok(bytes) => bytes,
+        Err(UuidError::EntropyError(_)) => return 1,
+        Err(_) => return 99,
+    };
+
+    match env.set_byte_array_region(&byte_array, 0, unsafe {
+        std::slice::from_raw_parts(bytes.as_ptr() as *const i8, 16)
+    }) {
+        Ok(_) => 0,
+        Err(_) => 2,
+    }
+}
+
+/// JNI function: Generate a new time-based UUID v1
+/// Java signature: private static native int nativeGenerateV1(byte[] nodeId, byte[] uuidBytes);
+///
+/// Embeds the current Gregorian-epoch timestamp and the caller-supplied
+/// `nodeId` (e.g. a MAC address). A process-global clock sequence is
+/// maintained so that two calls landing in the same or a backwards-moving
+/// tick still produce unique UUIDs.
+#[no_mangle]
+pub extern "system" fn Java_com_uuidgenerator_UuidGenerator_nativeGenerateV1(
+    env: JNIEnv,
+    _class: JClass,
+    node_id: jbyteArray,
+    uuid_bytes: jbyteArray,
+) -> jint {
+    let node_array = unsafe { JByteArray::from_raw(node_id) };
+    let out_array = unsafe { JByteArray::from_raw(uuid_bytes) };
+
+    let node_len = match env.get_array_length(&node_array) {
+        Ok(len) => len,
+        Err(_) => return 2,
+    };
+    let out_len = match env.get_array_length(&out_array) {
+        Ok(len) => len,
+        Err(_) => return 2,
+    };
+
+    if node_len != 6 || out_len != 16 {
+        return 2;
+    }
+
+    let mut node_buf = [0u8; 6];
+    match env.get_byte_array_region(&node_array, 0, unsafe {
+        std::slice::from_raw_parts_mut(node_buf.as_mut_ptr() as *mut i8, 6)
+    }) {
+        Ok(_) => {}
+        Err(_) => return 2,
+    }
+
+    let bytes = match sequencing::generate_v1(node_buf) {
+        Ok(bytes) => bytes,
+        Err(UuidError::EntropyError(_)) => return 1,
+        Err(_) => return 99,
+    };
+
+    match env.set_byte_array_region(&out_array, 0, unsafe {
+        std::slice::from_raw_parts(bytes.as_ptr() as *const i8, 16)
+    }) {
+        Ok(_) => 0,
+        Err(_) => 2,
+    }
+}
+
+/// JNI function: Generate `count` UUID v4s into a single contiguous buffer
+/// Java signature: private static native int nativeGenerateBatch(byte[] buffer, int count);
+///
+/// Crossing the JNI boundary once per UUID dominates the cost when
+/// generating thousands of IDs, so this draws all random bytes for the
+/// whole batch in one fill, then stamps the version and variant bits into
+/// each 16-byte slot.
+#[no_mangle]
+pub extern "system" fn Java_com_uuidgenerator_UuidGenerator_nativeGenerateBatch(
+    env: JNIEnv,
+    _class: JClass,
+    buffer: jbyteArray,
+    count: jint,
+) -> jint {
+    if count < 0 {
+        return 2;
+    }
+    let count = count as usize;
+
+    let buffer_array = unsafe { JByteArray::from_raw(buffer) };
+    let buffer_len = match env.get_array_length(&buffer_array) {
+        Ok(len) => len as usize,
+        Err(_) => return 2,
+    };
+
+    let total_bytes = match count.checked_mul(16) {
+        Some(n) => n,
+        None => return 2,
+    };
+
+    if buffer_len < total_bytes {
+        return 2;
+    }
+
+    if total_bytes == 0 {
+        return 0;
+    }
+
+    let mut raw_bytes = vec![0u8; total_bytes];
+    if let Err(e) = crate::entropy::fill_random_bytes(&mut raw_bytes) {
+        return match e {
+            UuidError::EntropyError(_) => 1,
+            _ => 99,
+        };
+    }
+
+    for slot in raw_bytes.chunks_exact_mut(16) {
+        slot[6] = (slot[6] & 0x0f) | 0x40;
+        slot[8] = (slot[8] & 0x3f) | 0x80;
+    }
+
+    match env.set_byte_array_region(&buffer_array, 0, unsafe {
+        std::slice::from_raw_parts(raw_bytes.as_ptr() as *const i8, total_bytes)
+    }) {
+        Ok(_) => 0,
+        Err(_) => 2,
+    }
+}
+
+/// JNI function: Generate a name-based UUID v5 (SHA-1) from a namespace and name
+/// Java signature: private static native int nativeGenerateV5(byte[] namespace, byte[] name, byte[] out);
+#[no_mangle]
+pub extern "system" fn Java_com_uuidgenerator_UuidGenerator_nativeGenerateV5(
+    env: JNIEnv,
+    class: JClass,
+    namespace: jbyteArray,
+    name: jbyteArray,
+    out: jbyteArray,
+) -> jint {
+    generate_name_based(env, class, namespace, name, out, Uuid::new_v5)
+}
+
+/// JNI function: Generate a name-based UUID v3 (MD5) from a namespace and name
+/// Java signature: private static native int nativeGenerateV3(byte[] namespace, byte[] name, byte[] out);
+#[no_mangle]
+pub extern "system" fn Java_com_uuidgenerator_UuidGenerator_nativeGenerateV3(
+    env: JNIEnv,
+    class: JClass,
+    namespace: jbyteArray,
+    name: jbyteArray,
+    out: jbyteArray,
+) -> jint {
+    generate_name_based(env, class, namespace, name, out, Uuid::new_v3)
+}
+
+/// Shared implementation backing `nativeGenerateV5` and `nativeGenerateV3`
+fn generate_name_based(
+    env: JNIEnv,
+    _class: JClass,
+    namespace: jbyteArray,
+    name: jbyteArray,
+    out: jbyteArray,
+    generate: impl Fn(&Uuid, &[u8]) -> Uuid,
+) -> jint {
+    let namespace_array = unsafe { JByteArray::from_raw(namespace) };
+    let name_array = unsafe { JByteArray::from_raw(name) };
+    let out_array = unsafe { JByteArray::from_raw(out) };
+
+    let namespace_len = match env.get_array_length(&namespace_array) {
+        Ok(len) => len,
+        Err(_) => return 2,
+    };
+    let name_len = match env.get_array_length(&name_array) {
+        Ok(len) => len,
+        Err(_) => return 2,
+    };
+    let out_len = match env.get_array_length(&out_array) {
+        Ok(len) => len,
+        Err(_) => return 2,
+    };
+
+    if namespace_len != 16 || out_len != 16 {
+        return 2;
+    }
+
+    let mut namespace_bytes = [0u8; 16];
+    match env.get_byte_array_region(&namespace_array, 0, unsafe {
+        std::slice::from_raw_parts_mut(namespace_bytes.as_mut_ptr() as *mut i8, 16)
+    }) {
+        Ok(_) => {}
+        Err(_) => return 2,
+    }
+
+    let mut name_buf = vec![0i8; name_len as usize];
+    if name_len > 0 {
+        match env.get_byte_array_region(&name_array, 0, &mut name_buf) {
+            Ok(_) => {}
+            Err(_) => return 2,
+        }
+    }
+    let name_bytes: Vec<u8> = name_buf.iter().map(|&b| b as u8).collect();
+
+    let namespace_uuid = Uuid::from_bytes(namespace_bytes);
+    let uuid = generate(&namespace_uuid, &name_bytes);
+
+    let result_bytes = *uuid.as_bytes();
+    match env.set_byte_array_region(&out_array, 0, unsafe {
+        std::slice::from_raw_parts(result_bytes.as_ptr() as *const i8, 16)
+    }) {
+        Ok(_) => 0,
+        Err(_) => 2,
+    }
+}
+
 /// JNI function: Convert UUID bytes to string
-/// Java signature: private static native int nativeToString(byte[] uuidBytes, byte[] buffer);
+/// Java signature: private static native int nativeToString(byte[] uuidBytes, byte[] buffer, int format);
+///
+/// `format` selects the textual representation: `0` Simple, `1` Hyphenated,
+/// `2` Urn, `3` Braced. The minimum required `buffer` length depends on the
+/// format: 32 / 36 / 45 / 38 bytes respectively (no null terminator needed,
+/// unlike the FFI `uuid_to_string` counterpart).
 #[no_mangle]
 pub extern "system" fn Java_com_uuidgenerator_UuidGenerator_nativeToString(
-    mut env: JNIEnv,
+    env: JNIEnv,
     _class: JClass,
     uuid_bytes: jbyteArray,
     buffer: jbyteArray,
+    format: jint,
 ) -> jint {
     let uuid_array = unsafe { JByteArray::from_raw(uuid_bytes) };
     let buffer_array = unsafe { JByteArray::from_raw(buffer) };
-    
+
     let uuid_len = match env.get_array_length(&uuid_array) {
         Ok(len) => len,
         Err(_) => return 2,
     };
-    
+
     let buffer_len = match env.get_array_length(&buffer_array) {
         Ok(len) => len,
         Err(_) => return 2,
     };
-    
+
     if uuid_len != 16 {
         return 2;
     }
-    
-    if buffer_len < 37 {
+
+    let min_len = match format {
+        0 => 32,
+        1 => 36,
+        2 => 45,
+        3 => 38,
+        _ => return 2,
+    };
+
+    if buffer_len < min_len {
         return 3;
     }
-    
+
     let mut uuid_bytes_buf = [0u8; 16];
     match env.get_byte_array_region(&uuid_array, 0, unsafe {
         std::slice::from_raw_parts_mut(uuid_bytes_buf.as_mut_ptr() as *mut i8, 16)
     }) {
-        Ok(_) => {},
+        Ok(_) => {}
         Err(_) => return 2,
     }
-    
-    let uuid = match Uuid::from_bytes(uuid_bytes_buf) {
-        Ok(uuid) => uuid,
-        Err(_) => return 2,
+
+    let uuid = Uuid::from_bytes(uuid_bytes_buf);
+
+    let uuid_string = match format {
+        0 => uuid.simple().to_string(),
+        1 => uuid.hyphenated().to_string(),
+        2 => uuid.urn().to_string(),
+        _ => uuid.braced().to_string(),
     };
-    
-    let uuid_string = uuid.to_string();
     let string_bytes = uuid_string.as_bytes();
-    
-    let mut output_buffer = vec![0i8; 37];
+
+    let mut output_buffer = vec![0i8; buffer_len as usize];
     for (i, &byte) in string_bytes.iter().enumerate() {
-        if i < 36 {
+        if i < output_buffer.len() {
             output_buffer[i] = byte as i8;
         }
     }
-    
+
     match env.set_byte_array_region(&buffer_array, 0, &output_buffer) {
         Ok(_) => 0,
         Err(_) => 2,
     }
 }
 
+/// JNI function: Parse a textual UUID into its canonical bytes
+/// Java signature: private static native int nativeParse(byte[] input, byte[] outBytes);
+///
+/// `input` holds the UTF-8 bytes of the UUID text. Accepts, case-insensitively,
+/// the hyphenated form, the simple/unhyphenated form, the URN form
+/// (`urn:uuid:...`), and the braced Microsoft GUID form (`{...}`).
+#[no_mangle]
+pub extern "system" fn Java_com_uuidgenerator_UuidGenerator_nativeParse(
+    env: JNIEnv,
+    _class: JClass,
+    input: jbyteArray,
+    out_bytes: jbyteArray,
+) -> jint {
+    let input_array = unsafe { JByteArray::from_raw(input) };
+    let out_array = unsafe { JByteArray::from_raw(out_bytes) };
+
+    let input_len = match env.get_array_length(&input_array) {
+        Ok(len) => len,
+        Err(_) => return 2,
+    };
+    let out_len = match env.get_array_length(&out_array) {
+        Ok(len) => len,
+        Err(_) => return 2,
+    };
+
+    if out_len != 16 {
+        return 2;
+    }
+
+    let mut input_buf = vec![0i8; input_len as usize];
+    if input_len > 0 {
+        match env.get_byte_array_region(&input_array, 0, &mut input_buf) {
+            Ok(_) => {}
+            Err(_) => return 2,
+        }
+    }
+    let input_bytes: Vec<u8> = input_buf.iter().map(|&b| b as u8).collect();
+
+    let input_str = match std::str::from_utf8(&input_bytes) {
+        Ok(s) => s,
+        Err(_) => return 2,
+    };
+
+    let uuid = match Uuid::parse_str(input_str) {
+        Ok(uuid) => uuid,
+        Err(_) => return 2,
+    };
+
+    let result_bytes = *uuid.as_bytes();
+    match env.set_byte_array_region(&out_array, 0, unsafe {
+        std::slice::from_raw_parts(result_bytes.as_ptr() as *const i8, 16)
+    }) {
+        Ok(_) => 0,
+        Err(_) => 2,
+    }
+}
+
 /// JNI function: Get UUID info (version and variant)
 /// Java signature: private static native int nativeGetInfo(byte[] uuidBytes, byte[] info);
 #[no_mangle]
 pub extern "system" fn Java_com_uuidgenerator_UuidGenerator_nativeGetInfo(
-    mut env: JNIEnv,
+    env: JNIEnv,
     _class: JClass,
     uuid_bytes: jbyteArray,
     info: jbyteArray,
@@ -136,11 +438,8 @@ pub extern "system" fn Java_com_uuidgenerator_UuidGenerator_nativeGetInfo(
         Err(_) => return 2,
     }
     
-    let uuid = match Uuid::from_bytes(uuid_bytes_buf) {
-        Ok(uuid) => uuid,
-        Err(_) => return 2,
-    };
-    
+    let uuid = Uuid::from_bytes(uuid_bytes_buf);
+
     let version = uuid.version() as i8;
     let variant = uuid.variant() as i8;
     
@@ -155,7 +454,7 @@ pub extern "system" fn Java_com_uuidgenerator_UuidGenerator_nativeGetInfo(
 /// Java signature: private static native int nativeCompare(byte[] uuid1Bytes, byte[] uuid2Bytes, byte[] result);
 #[no_mangle]
 pub extern "system" fn Java_com_uuidgenerator_UuidGenerator_nativeCompare(
-    mut env: JNIEnv,
+    env: JNIEnv,
     _class: JClass,
     uuid1_bytes: jbyteArray,
     uuid2_bytes: jbyteArray,
@@ -203,19 +502,101 @@ pub extern "system" fn Java_com_uuidgenerator_UuidGenerator_nativeCompare(
         Err(_) => return 2,
     }
     
-    let uuid1 = match Uuid::from_bytes(uuid1_bytes_buf) {
-        Ok(uuid) => uuid,
+    let uuid1 = Uuid::from_bytes(uuid1_bytes_buf);
+    let uuid2 = Uuid::from_bytes(uuid2_bytes_buf);
+
+    let are_equal = if uuid1 == uuid2 { 1i8 } else { 0i8 };
+
+    match env.set_byte_array_region(&result_array, 0, &[are_equal]) {
+        Ok(_) => 0,
+        Err(_) => 2,
+    }
+}
+
+/// JNI function: Convert canonical big-endian UUID bytes to the
+/// little-endian GUID field layout used by Windows/.NET/COM
+/// Java signature: private static native int nativeToGuidBytes(byte[] uuidBytes, byte[] guidBytes);
+#[no_mangle]
+pub extern "system" fn Java_com_uuidgenerator_UuidGenerator_nativeToGuidBytes(
+    env: JNIEnv,
+    _class: JClass,
+    uuid_bytes: jbyteArray,
+    guid_bytes: jbyteArray,
+) -> jint {
+    let uuid_array = unsafe { JByteArray::from_raw(uuid_bytes) };
+    let guid_array = unsafe { JByteArray::from_raw(guid_bytes) };
+
+    let uuid_len = match env.get_array_length(&uuid_array) {
+        Ok(len) => len,
         Err(_) => return 2,
     };
-    
-    let uuid2 = match Uuid::from_bytes(uuid2_bytes_buf) {
-        Ok(uuid) => uuid,
+    let guid_len = match env.get_array_length(&guid_array) {
+        Ok(len) => len,
         Err(_) => return 2,
     };
-    
-    let are_equal = if uuid1 == uuid2 { 1i8 } else { 0i8 };
-    
-    match env.set_byte_array_region(&result_array, 0, &[are_equal]) {
+
+    if uuid_len != 16 || guid_len != 16 {
+        return 2;
+    }
+
+    let mut uuid_buf = [0u8; 16];
+    match env.get_byte_array_region(&uuid_array, 0, unsafe {
+        std::slice::from_raw_parts_mut(uuid_buf.as_mut_ptr() as *mut i8, 16)
+    }) {
+        Ok(_) => {}
+        Err(_) => return 2,
+    }
+
+    let uuid = Uuid::from_bytes(uuid_buf);
+    let guid_bytes_le = uuid.to_bytes_le();
+
+    match env.set_byte_array_region(&guid_array, 0, unsafe {
+        std::slice::from_raw_parts(guid_bytes_le.as_ptr() as *const i8, 16)
+    }) {
+        Ok(_) => 0,
+        Err(_) => 2,
+    }
+}
+
+/// JNI function: Convert little-endian GUID-ordered bytes (Windows/.NET/COM)
+/// to the canonical big-endian UUID representation
+/// Java signature: private static native int nativeFromGuidBytes(byte[] guidBytes, byte[] uuidBytes);
+#[no_mangle]
+pub extern "system" fn Java_com_uuidgenerator_UuidGenerator_nativeFromGuidBytes(
+    env: JNIEnv,
+    _class: JClass,
+    guid_bytes: jbyteArray,
+    uuid_bytes: jbyteArray,
+) -> jint {
+    let guid_array = unsafe { JByteArray::from_raw(guid_bytes) };
+    let uuid_array = unsafe { JByteArray::from_raw(uuid_bytes) };
+
+    let guid_len = match env.get_array_length(&guid_array) {
+        Ok(len) => len,
+        Err(_) => return 2,
+    };
+    let uuid_len = match env.get_array_length(&uuid_array) {
+        Ok(len) => len,
+        Err(_) => return 2,
+    };
+
+    if guid_len != 16 || uuid_len != 16 {
+        return 2;
+    }
+
+    let mut guid_buf = [0u8; 16];
+    match env.get_byte_array_region(&guid_array, 0, unsafe {
+        std::slice::from_raw_parts_mut(guid_buf.as_mut_ptr() as *mut i8, 16)
+    }) {
+        Ok(_) => {}
+        Err(_) => return 2,
+    }
+
+    let uuid = Uuid::from_bytes_le(guid_buf);
+
+    match env.set_byte_array_region(&uuid_array, 0, unsafe {
+        std::slice::from_raw_parts(uuid.as_bytes().as_ptr() as *const i8, 16)
+    }) {
         Ok(_) => 0,
         Err(_) => 2,
     }
@@ -21,11 +21,25 @@
 //! println!("Generated UUID: {}", uuid);
 //! ```
 
+pub mod adapters;
 pub mod ffi;
+#[cfg(feature = "jni")]
+pub mod jni;
+mod builder;
+mod entropy;
+mod md5;
+mod sequencing;
+mod sha1;
+mod timestamp;
+#[cfg(feature = "serde")]
+mod serde_support;
+
+pub use builder::Builder;
+pub use timestamp::Timestamp;
 
 use std::fmt;
-use std::fs::File;
-use std::io::Read;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// UUID structure representing a 128-bit universally unique identifier
 /// 
@@ -63,7 +77,67 @@ impl fmt::Display for UuidError {
 
 impl std::error::Error for UuidError {}
 
+/// The version of a UUID, as a typed alternative to the bare `u8` returned
+/// by [`Uuid::version`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Version {
+    /// Special case for the nil UUID (all zero bits)
+    Nil,
+    /// Version 1: Gregorian timestamp and node ID
+    TimeBased,
+    /// Version 2: DCE Security
+    DceSecurity,
+    /// Version 3: Name-based, MD5 hashed
+    Md5,
+    /// Version 4: Random
+    Random,
+    /// Version 5: Name-based, SHA-1 hashed
+    Sha1,
+    /// Version 6: Reordered Gregorian timestamp
+    SortMacTime,
+    /// Version 7: Unix Epoch timestamp
+    SortRand,
+    /// Version 8: Custom/vendor-defined
+    Custom,
+    /// Special case for the max UUID (all one bits)
+    Max,
+    /// Any version value not covered above
+    Unknown(u8),
+}
+
 impl Uuid {
+    /// The DNS namespace, for UUIDs derived from fully-qualified domain names
+    pub const NAMESPACE_DNS: Uuid = Uuid {
+        bytes: [
+            0x6b, 0xa7, 0xb8, 0x10, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4,
+            0x30, 0xc8,
+        ],
+    };
+
+    /// The URL namespace, for UUIDs derived from URLs
+    pub const NAMESPACE_URL: Uuid = Uuid {
+        bytes: [
+            0x6b, 0xa7, 0xb8, 0x11, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4,
+            0x30, 0xc8,
+        ],
+    };
+
+    /// The ISO OID namespace, for UUIDs derived from OIDs
+    pub const NAMESPACE_OID: Uuid = Uuid {
+        bytes: [
+            0x6b, 0xa7, 0xb8, 0x12, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4,
+            0x30, 0xc8,
+        ],
+    };
+
+    /// The X.500 DN namespace, for UUIDs derived from X.500 distinguished names
+    pub const NAMESPACE_X500: Uuid = Uuid {
+        bytes: [
+            0x6b, 0xa7, 0xb8, 0x14, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4,
+            0x30, 0xc8,
+        ],
+    };
+
     /// Creates a new UUID v4 using cryptographically secure random data
     /// 
     /// This function demonstrates the complete UUID v4 generation process:
@@ -101,28 +175,21 @@ impl Uuid {
     }
     
     /// Fills a byte array with cryptographically secure random data from system entropy
-    /// 
-    /// This function demonstrates how to collect entropy without external dependencies:
-    /// - On Unix-like systems: reads from /dev/urandom
-    /// - Implements proper error handling for entropy collection failures
-    /// 
+    ///
+    /// This function demonstrates how to collect entropy without external dependencies,
+    /// dispatching to the platform-appropriate backend in the `entropy` module:
+    /// - Windows: `BCryptGenRandom`
+    /// - Linux: the `getrandom(2)` syscall, falling back to `/dev/urandom`
+    /// - Other Unix: reads from `/dev/urandom`
+    ///
     /// # Arguments
     /// - `buffer` - Mutable byte slice to fill with random data
-    /// 
+    ///
     /// # Returns
     /// - `Ok(())` - Successfully filled buffer with random data
     /// - `Err(UuidError)` - If entropy source is unavailable or fails
     fn fill_random_bytes(buffer: &mut [u8]) -> Result<(), UuidError> {
-        // Use /dev/urandom for cryptographically secure random bytes
-        // /dev/urandom is preferred over /dev/random as it doesn't block
-        // and provides cryptographically secure pseudorandom data
-        let mut file = File::open("/dev/urandom")
-            .map_err(|e| UuidError::EntropyError(format!("Failed to open /dev/urandom: {}", e)))?;
-            
-        file.read_exact(buffer)
-            .map_err(|e| UuidError::EntropyError(format!("Failed to read random bytes: {}", e)))?;
-            
-        Ok(())
+        entropy::fill_random_bytes(buffer)
     }
     
     /// Returns the raw bytes of the UUID in big-endian order
@@ -154,16 +221,363 @@ impl Uuid {
         }
     }
     
+    /// Returns the version of the UUID as a typed [`Version`] enum
+    ///
+    /// Unlike [`Uuid::version`], this distinguishes the nil and max special
+    /// cases and gives each RFC 4122/9562 version a self-documenting name.
+    pub fn get_version(&self) -> Version {
+        if self.is_nil() {
+            return Version::Nil;
+        }
+        if self.is_max() {
+            return Version::Max;
+        }
+
+        match self.version() {
+            1 => Version::TimeBased,
+            2 => Version::DceSecurity,
+            3 => Version::Md5,
+            4 => Version::Random,
+            5 => Version::Sha1,
+            6 => Version::SortMacTime,
+            7 => Version::SortRand,
+            8 => Version::Custom,
+            other => Version::Unknown(other),
+        }
+    }
+
+    /// The nil UUID, with all 128 bits set to zero
+    pub const fn nil() -> Self {
+        Uuid { bytes: [0u8; 16] }
+    }
+
+    /// The max UUID, with all 128 bits set to one
+    pub const fn max() -> Self {
+        Uuid { bytes: [0xffu8; 16] }
+    }
+
+    /// Returns `true` if this is the nil UUID (all zero bits)
+    pub fn is_nil(&self) -> bool {
+        self.bytes == [0u8; 16]
+    }
+
+    /// Returns `true` if this is the max UUID (all one bits)
+    pub fn is_max(&self) -> bool {
+        self.bytes == [0xffu8; 16]
+    }
+
     /// Creates a UUID from a byte array
-    /// 
+    ///
     /// # Arguments
     /// - `bytes` - 16-byte array containing UUID data
-    /// 
+    ///
     /// # Returns
     /// A new UUID instance
     pub fn from_bytes(bytes: [u8; 16]) -> Self {
         Uuid { bytes }
     }
+
+    /// Creates a new time-ordered UUID v7 (RFC 9562)
+    ///
+    /// Unlike v4, v7 UUIDs sort lexicographically by creation time, which
+    /// makes them far friendlier to B-tree database indexes. Layout:
+    /// - bytes 0-5: 48-bit big-endian Unix timestamp in milliseconds
+    /// - byte 6 upper nibble: version (7); lower 12 bits (with byte 7): random
+    /// - byte 8 upper 2 bits: variant (0b10); remaining bits: random
+    /// - bytes 9-15: random
+    ///
+    /// # Returns
+    /// - `Ok(Uuid)` - A newly generated UUID v7
+    /// - `Err(UuidError)` - If entropy collection or reading the system clock fails
+    ///
+    /// # Example
+    /// ```rust
+    /// # use uuid_generator::Uuid;
+    /// let uuid = Uuid::new_v7().expect("Failed to generate UUID");
+    /// println!("Generated UUID: {}", uuid);
+    /// ```
+    pub fn new_v7() -> Result<Self, UuidError> {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| {
+                UuidError::EntropyError(format!("System clock is before UNIX epoch: {}", e))
+            })?
+            .as_millis() as u64;
+
+        let mut random_bytes = [0u8; 10];
+        Self::fill_random_bytes(&mut random_bytes)?;
+
+        let mut bytes = [0u8; 16];
+        let ts_bytes = millis.to_be_bytes();
+        bytes[0..6].copy_from_slice(&ts_bytes[2..8]);
+
+        bytes[6] = 0x70 | (random_bytes[0] & 0x0f);
+        bytes[7] = random_bytes[1];
+
+        bytes[8] = 0x80 | (random_bytes[2] & 0x3f);
+        bytes[9..16].copy_from_slice(&random_bytes[3..10]);
+
+        Ok(Uuid { bytes })
+    }
+
+    /// Extracts the 48-bit millisecond Unix timestamp embedded in a v7 UUID
+    ///
+    /// # Returns
+    /// - `Some(millis)` - The creation timestamp, for UUIDs with version 7
+    /// - `None` - For any other UUID version
+    pub fn get_timestamp(&self) -> Option<u64> {
+        if self.version() != 7 {
+            return None;
+        }
+
+        let mut ts_bytes = [0u8; 8];
+        ts_bytes[2..8].copy_from_slice(&self.bytes[0..6]);
+        Some(u64::from_be_bytes(ts_bytes))
+    }
+
+    /// Returns the 32-character form with no hyphens
+    pub fn simple(&self) -> adapters::Simple<'_> {
+        adapters::Simple(self)
+    }
+
+    /// Returns the standard 8-4-4-4-12 hyphenated form (identical to `Display`)
+    pub fn hyphenated(&self) -> adapters::Hyphenated<'_> {
+        adapters::Hyphenated(self)
+    }
+
+    /// Returns the URN form, prefixed with `urn:uuid:`
+    pub fn urn(&self) -> adapters::Urn<'_> {
+        adapters::Urn(self)
+    }
+
+    /// Returns the braced Microsoft GUID form, wrapped in `{}`
+    pub fn braced(&self) -> adapters::Braced<'_> {
+        adapters::Braced(self)
+    }
+
+    /// Returns the UUID bytes reordered into the little-endian GUID field
+    /// layout used by Windows/.NET/COM (`Data1`/`Data2`/`Data3` byte-swapped,
+    /// `Data4` left in order)
+    pub fn to_bytes_le(&self) -> [u8; 16] {
+        let b = &self.bytes;
+        [
+            b[3], b[2], b[1], b[0], b[5], b[4], b[7], b[6], b[8], b[9], b[10], b[11], b[12],
+            b[13], b[14], b[15],
+        ]
+    }
+
+    /// Creates a UUID from bytes in the little-endian GUID field layout used
+    /// by Windows/.NET/COM, reordering them back into big-endian form
+    pub fn from_bytes_le(bytes: [u8; 16]) -> Self {
+        Uuid {
+            bytes: [
+                bytes[3], bytes[2], bytes[1], bytes[0], bytes[5], bytes[4], bytes[7], bytes[6],
+                bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14],
+                bytes[15],
+            ],
+        }
+    }
+
+    /// Creates a new time-based UUID v1 (RFC 4122/9562)
+    ///
+    /// Encodes the current time as a 60-bit count of 100-nanosecond
+    /// intervals since the Gregorian epoch (1582-10-15), split across
+    /// `time_low`, `time_mid` and `time_hi_and_version` (bytes 0-7); the
+    /// given `clock_seq` fills bytes 8-9 alongside the variant bits; and
+    /// `node_id` (typically a MAC address) is copied into bytes 10-15.
+    ///
+    /// # Arguments
+    /// - `node_id` - A 6-byte node identifier, e.g. a MAC address
+    /// - `clock_seq` - A 14-bit clock sequence (upper bits are discarded);
+    ///   callers that generate many v1 UUIDs should increment this whenever
+    ///   the clock moves backwards to avoid collisions
+    ///
+    /// # Returns
+    /// - `Ok(Uuid)` - A newly generated UUID v1
+    /// - `Err(UuidError)` - If reading the system clock fails
+    pub fn new_v1(node_id: [u8; 6], clock_seq: u16) -> Result<Self, UuidError> {
+        let timestamp = Timestamp::now()?;
+        let (time_low, time_mid, time_hi) = timestamp.to_fields();
+
+        let mut bytes = [0u8; 16];
+        bytes[0..4].copy_from_slice(&time_low.to_be_bytes());
+        bytes[4..6].copy_from_slice(&time_mid.to_be_bytes());
+
+        let time_hi_and_version = (time_hi & 0x0fff) | 0x1000;
+        bytes[6..8].copy_from_slice(&time_hi_and_version.to_be_bytes());
+
+        let clock_seq = clock_seq & 0x3fff;
+        bytes[8] = ((clock_seq >> 8) as u8 & 0x3f) | 0x80;
+        bytes[9] = (clock_seq & 0xff) as u8;
+
+        bytes[10..16].copy_from_slice(&node_id);
+
+        Ok(Uuid { bytes })
+    }
+
+    /// Extracts the Gregorian-epoch [`Timestamp`] embedded in a v1 UUID
+    ///
+    /// # Returns
+    /// - `Some(Timestamp)` - The creation timestamp, for UUIDs with version 1
+    /// - `None` - For any other UUID version
+    pub fn get_v1_timestamp(&self) -> Option<Timestamp> {
+        if self.version() != 1 {
+            return None;
+        }
+
+        let time_low = u32::from_be_bytes([self.bytes[0], self.bytes[1], self.bytes[2], self.bytes[3]]);
+        let time_mid = u16::from_be_bytes([self.bytes[4], self.bytes[5]]);
+        let time_hi_and_version = u16::from_be_bytes([self.bytes[6], self.bytes[7]]);
+
+        Some(Timestamp::from_fields(time_low, time_mid, time_hi_and_version))
+    }
+
+    /// Creates a name-based UUID v5 using SHA-1 hashing (RFC 4122/9562)
+    ///
+    /// The same `namespace` and `name` always produce the same UUID, which
+    /// makes v5 useful for deriving stable identifiers from external data
+    /// such as URLs or DNS names.
+    ///
+    /// # Arguments
+    /// - `namespace` - The namespace UUID (see the `NAMESPACE_*` constants)
+    /// - `name` - The name to derive the UUID from
+    ///
+    /// # Example
+    /// ```rust
+    /// # use uuid_generator::Uuid;
+    /// let uuid = Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"example.com");
+    /// assert_eq!(uuid.version(), 5);
+    /// ```
+    pub fn new_v5(namespace: &Uuid, name: &[u8]) -> Uuid {
+        let mut data = Vec::with_capacity(16 + name.len());
+        data.extend_from_slice(namespace.as_bytes());
+        data.extend_from_slice(name);
+
+        let hash = sha1::digest(&data);
+
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&hash[..16]);
+
+        bytes[6] = (bytes[6] & 0x0f) | 0x50;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+        Uuid { bytes }
+    }
+
+    /// Creates a name-based UUID v3 using MD5 hashing (RFC 4122/9562)
+    ///
+    /// Equivalent to [`Uuid::new_v5`] but uses MD5 instead of SHA-1, as
+    /// specified for version 3. Prefer v5 for new uses; v3 exists mainly
+    /// for interoperability with systems that already generate it.
+    ///
+    /// # Arguments
+    /// - `namespace` - The namespace UUID (see the `NAMESPACE_*` constants)
+    /// - `name` - The name to derive the UUID from
+    pub fn new_v3(namespace: &Uuid, name: &[u8]) -> Uuid {
+        let mut data = Vec::with_capacity(16 + name.len());
+        data.extend_from_slice(namespace.as_bytes());
+        data.extend_from_slice(name);
+
+        let hash = md5::digest(&data);
+
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&hash);
+
+        bytes[6] = (bytes[6] & 0x0f) | 0x30;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+        Uuid { bytes }
+    }
+
+    /// Parses a UUID from its textual representation
+    ///
+    /// Accepts, case-insensitively:
+    /// - The hyphenated form: `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`
+    /// - The simple form: 32 hex characters with no hyphens
+    /// - The URN form: `urn:uuid:xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`
+    /// - The braced form: `{xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx}`
+    ///
+    /// # Arguments
+    /// - `input` - The string to parse
+    ///
+    /// # Returns
+    /// - `Ok(Uuid)` - Successfully parsed UUID
+    /// - `Err(UuidError::InvalidFormat)` - If the input is not a valid UUID string
+    ///
+    /// # Example
+    /// ```rust
+    /// # use uuid_generator::Uuid;
+    /// let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
+    /// assert_eq!(uuid.to_string(), "550e8400-e29b-41d4-a716-446655440000");
+    /// ```
+    pub fn parse_str(input: &str) -> Result<Self, UuidError> {
+        let s = input.trim();
+
+        let s = if s.len() >= 9 && s.as_bytes()[..9].eq_ignore_ascii_case(b"urn:uuid:") {
+            &s[9..]
+        } else {
+            s
+        };
+
+        let s = if s.starts_with('{') && s.ends_with('}') {
+            &s[1..s.len() - 1]
+        } else {
+            s
+        };
+
+        let mut bytes = [0u8; 16];
+        let mut high_nibble: Option<u8> = None;
+        let mut digit_count = 0usize;
+        let mut byte_index = 0usize;
+
+        for (i, c) in s.chars().enumerate() {
+            if c == '-' {
+                if !matches!(i, 8 | 13 | 18 | 23) {
+                    return Err(UuidError::InvalidFormat(format!(
+                        "unexpected hyphen at position {}",
+                        i
+                    )));
+                }
+                continue;
+            }
+
+            let nibble = c
+                .to_digit(16)
+                .ok_or_else(|| UuidError::InvalidFormat(format!("invalid hex digit '{}'", c)))?
+                as u8;
+            digit_count += 1;
+
+            match high_nibble.take() {
+                None => high_nibble = Some(nibble),
+                Some(high) => {
+                    if byte_index >= 16 {
+                        return Err(UuidError::InvalidFormat(
+                            "too many hex digits".to_string(),
+                        ));
+                    }
+                    bytes[byte_index] = (high << 4) | nibble;
+                    byte_index += 1;
+                }
+            }
+        }
+
+        if digit_count != 32 {
+            return Err(UuidError::InvalidFormat(format!(
+                "expected 32 hex digits, found {}",
+                digit_count
+            )));
+        }
+
+        Ok(Uuid { bytes })
+    }
+}
+
+impl FromStr for Uuid {
+    type Err = UuidError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::parse_str(s)
+    }
 }
 
 impl fmt::Display for Uuid {
@@ -240,6 +654,304 @@ mod tests {
         assert_eq!(uuid.version(), 4); // Version extracted from byte 6
     }
     
+    #[test]
+    fn test_parse_str_hyphenated() {
+        let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")
+            .expect("Should parse hyphenated form");
+        assert_eq!(uuid.to_string(), "550e8400-e29b-41d4-a716-446655440000");
+    }
+
+    #[test]
+    fn test_parse_str_simple() {
+        let uuid = Uuid::parse_str("550e8400e29b41d4a716446655440000")
+            .expect("Should parse simple form");
+        assert_eq!(uuid.to_string(), "550e8400-e29b-41d4-a716-446655440000");
+    }
+
+    #[test]
+    fn test_parse_str_urn() {
+        let uuid = Uuid::parse_str("urn:uuid:550e8400-e29b-41d4-a716-446655440000")
+            .expect("Should parse URN form");
+        assert_eq!(uuid.to_string(), "550e8400-e29b-41d4-a716-446655440000");
+    }
+
+    #[test]
+    fn test_parse_str_braced() {
+        let uuid = Uuid::parse_str("{550e8400-e29b-41d4-a716-446655440000}")
+            .expect("Should parse braced form");
+        assert_eq!(uuid.to_string(), "550e8400-e29b-41d4-a716-446655440000");
+    }
+
+    #[test]
+    fn test_parse_str_case_insensitive() {
+        let uuid = Uuid::parse_str("550E8400-E29B-41D4-A716-446655440000")
+            .expect("Should parse uppercase hex");
+        assert_eq!(uuid.to_string(), "550e8400-e29b-41d4-a716-446655440000");
+    }
+
+    #[test]
+    fn test_parse_str_wrong_length() {
+        let result = Uuid::parse_str("550e8400-e29b-41d4-a716");
+        assert!(matches!(result, Err(UuidError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn test_parse_str_misplaced_hyphen() {
+        let result = Uuid::parse_str("550e840-0e29b-41d4-a716-446655440000");
+        assert!(matches!(result, Err(UuidError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn test_parse_str_invalid_hex() {
+        let result = Uuid::parse_str("550e8400-e29b-41d4-a716-44665544000g");
+        assert!(matches!(result, Err(UuidError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn test_from_str_trait() {
+        let uuid: Uuid = "550e8400-e29b-41d4-a716-446655440000"
+            .parse()
+            .expect("Should parse via FromStr");
+        assert_eq!(uuid.to_string(), "550e8400-e29b-41d4-a716-446655440000");
+    }
+
+    #[test]
+    fn test_round_trip_v4() {
+        let uuid = Uuid::new_v4().expect("Should generate UUID");
+        let parsed = Uuid::parse_str(&uuid.to_string()).expect("Should round-trip");
+        assert_eq!(uuid, parsed);
+    }
+
+    #[test]
+    fn test_new_v5_known_vector() {
+        let uuid = Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"example.com");
+        assert_eq!(uuid.to_string(), "cfbff0d1-9375-5685-968c-48ce8b15ae17");
+        assert_eq!(uuid.version(), 5);
+        assert_eq!(uuid.variant(), 2);
+    }
+
+    #[test]
+    fn test_new_v5_deterministic() {
+        let uuid1 = Uuid::new_v5(&Uuid::NAMESPACE_URL, b"https://example.com");
+        let uuid2 = Uuid::new_v5(&Uuid::NAMESPACE_URL, b"https://example.com");
+        assert_eq!(uuid1, uuid2, "Same namespace and name should produce the same UUID");
+    }
+
+    #[test]
+    fn test_new_v3_version_and_variant() {
+        let uuid = Uuid::new_v3(&Uuid::NAMESPACE_DNS, b"example.com");
+        assert_eq!(uuid.version(), 3, "UUID version should be 3");
+        assert_eq!(uuid.variant(), 2, "UUID variant should be 2 (RFC 4122)");
+    }
+
+    #[test]
+    fn test_new_v3_deterministic() {
+        let uuid1 = Uuid::new_v3(&Uuid::NAMESPACE_OID, b"1.2.3");
+        let uuid2 = Uuid::new_v3(&Uuid::NAMESPACE_OID, b"1.2.3");
+        assert_eq!(uuid1, uuid2, "Same namespace and name should produce the same UUID");
+    }
+
+    #[test]
+    fn test_new_v3_v5_differ() {
+        let v3 = Uuid::new_v3(&Uuid::NAMESPACE_X500, b"CN=example");
+        let v5 = Uuid::new_v5(&Uuid::NAMESPACE_X500, b"CN=example");
+        assert_ne!(v3, v5, "v3 and v5 use different hashes and should differ");
+    }
+
+    #[test]
+    fn test_new_v7_version_and_variant() {
+        let uuid = Uuid::new_v7().expect("Should generate UUID v7 successfully");
+        assert_eq!(uuid.version(), 7, "UUID version should be 7");
+        assert_eq!(uuid.variant(), 2, "UUID variant should be 2 (RFC 4122)");
+    }
+
+    #[test]
+    fn test_new_v7_timestamp_roundtrip() {
+        let before = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+
+        let uuid = Uuid::new_v7().expect("Should generate UUID v7 successfully");
+
+        let after = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+
+        let timestamp = uuid.get_timestamp().expect("v7 UUID should have a timestamp");
+        assert!(
+            timestamp >= before && timestamp <= after,
+            "Extracted timestamp {} should fall within [{}, {}]",
+            timestamp,
+            before,
+            after
+        );
+    }
+
+    #[test]
+    fn test_get_timestamp_none_for_other_versions() {
+        let uuid = Uuid::new_v4().expect("Should generate UUID v4 successfully");
+        assert_eq!(uuid.get_timestamp(), None, "v4 UUIDs have no embedded timestamp");
+    }
+
+    #[test]
+    fn test_new_v7_ordering() {
+        let uuid1 = Uuid::new_v7().expect("Should generate first UUID v7");
+        let uuid2 = Uuid::new_v7().expect("Should generate second UUID v7");
+        assert!(
+            uuid1.get_timestamp().unwrap() <= uuid2.get_timestamp().unwrap(),
+            "Later v7 UUIDs should have timestamps that do not decrease"
+        );
+    }
+
+    #[test]
+    fn test_format_adapters() {
+        let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
+
+        assert_eq!(uuid.simple().to_string(), "550e8400e29b41d4a716446655440000");
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "550e8400-e29b-41d4-a716-446655440000"
+        );
+        assert_eq!(
+            uuid.urn().to_string(),
+            "urn:uuid:550e8400-e29b-41d4-a716-446655440000"
+        );
+        assert_eq!(
+            uuid.braced().to_string(),
+            "{550e8400-e29b-41d4-a716-446655440000}"
+        );
+    }
+
+    #[test]
+    fn test_format_adapters_uppercase() {
+        let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
+
+        assert_eq!(
+            format!("{:X}", uuid.simple()),
+            "550E8400E29B41D4A716446655440000"
+        );
+        assert_eq!(
+            format!("{:X}", uuid.hyphenated()),
+            "550E8400-E29B-41D4-A716-446655440000"
+        );
+        assert_eq!(
+            format!("{:X}", uuid.urn()),
+            "urn:uuid:550E8400-E29B-41D4-A716-446655440000"
+        );
+        assert_eq!(
+            format!("{:X}", uuid.braced()),
+            "{550E8400-E29B-41D4-A716-446655440000}"
+        );
+    }
+
+    #[test]
+    fn test_bytes_le_roundtrip() {
+        let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
+        let le_bytes = uuid.to_bytes_le();
+        let roundtripped = Uuid::from_bytes_le(le_bytes);
+        assert_eq!(uuid, roundtripped);
+    }
+
+    #[test]
+    fn test_to_bytes_le_swaps_fields() {
+        let uuid = Uuid::parse_str("00112233-4455-6677-8899-aabbccddeeff").unwrap();
+        let le_bytes = uuid.to_bytes_le();
+        assert_eq!(
+            le_bytes,
+            [
+                0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xaa, 0xbb, 0xcc,
+                0xdd, 0xee, 0xff
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nil_and_max() {
+        let nil = Uuid::nil();
+        let max = Uuid::max();
+
+        assert!(nil.is_nil());
+        assert!(!nil.is_max());
+        assert_eq!(nil.as_bytes(), &[0u8; 16]);
+
+        assert!(max.is_max());
+        assert!(!max.is_nil());
+        assert_eq!(max.as_bytes(), &[0xffu8; 16]);
+    }
+
+    #[test]
+    fn test_get_version_special_cases() {
+        assert_eq!(Uuid::nil().get_version(), Version::Nil);
+        assert_eq!(Uuid::max().get_version(), Version::Max);
+    }
+
+    #[test]
+    fn test_get_version_known_versions() {
+        assert_eq!(Uuid::new_v4().unwrap().get_version(), Version::Random);
+        assert_eq!(Uuid::new_v7().unwrap().get_version(), Version::SortRand);
+        assert_eq!(
+            Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"example.com").get_version(),
+            Version::Sha1
+        );
+        assert_eq!(
+            Uuid::new_v3(&Uuid::NAMESPACE_DNS, b"example.com").get_version(),
+            Version::Md5
+        );
+    }
+
+    #[test]
+    fn test_builder_roundtrip_via_public_api() {
+        let bytes = *Uuid::new_v4().unwrap().as_bytes();
+        let uuid = Builder::from_bytes(bytes)
+            .with_version(4)
+            .with_variant()
+            .build();
+
+        assert_eq!(uuid.get_version(), Version::Random);
+        assert_eq!(uuid.variant(), 2);
+    }
+
+    #[test]
+    fn test_new_v1_version_and_variant() {
+        let node_id = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+        let uuid = Uuid::new_v1(node_id, 0x1234).expect("Should generate UUID v1 successfully");
+
+        assert_eq!(uuid.version(), 1, "UUID version should be 1");
+        assert_eq!(uuid.variant(), 2, "UUID variant should be 2 (RFC 4122)");
+    }
+
+    #[test]
+    fn test_new_v1_preserves_node_id() {
+        let node_id = [0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff];
+        let uuid = Uuid::new_v1(node_id, 0).expect("Should generate UUID v1 successfully");
+
+        assert_eq!(&uuid.as_bytes()[10..16], &node_id);
+    }
+
+    #[test]
+    fn test_new_v1_timestamp_roundtrip() {
+        let before = Timestamp::now().unwrap().to_ticks();
+        let uuid = Uuid::new_v1([0u8; 6], 0).expect("Should generate UUID v1 successfully");
+        let after = Timestamp::now().unwrap().to_ticks();
+
+        let timestamp = uuid.get_v1_timestamp().expect("v1 UUID should have a timestamp");
+        assert!(
+            timestamp.to_ticks() >= before && timestamp.to_ticks() <= after,
+            "Extracted timestamp {} should fall within [{}, {}]",
+            timestamp.to_ticks(),
+            before,
+            after
+        );
+    }
+
+    #[test]
+    fn test_get_v1_timestamp_none_for_other_versions() {
+        let uuid = Uuid::new_v4().expect("Should generate UUID v4 successfully");
+        assert_eq!(uuid.get_v1_timestamp(), None, "v4 UUIDs have no v1 timestamp");
+    }
+
     #[test]
     fn test_multiple_generations() {
         // Generate multiple UUIDs to test consistency
@@ -0,0 +1,125 @@
+//! Shared monotonic state for time-based UUID generation across the FFI and
+//! JNI boundaries
+//!
+//! `uuid_generate_v7`/`nativeGenerateV7` and `uuid_generate_v1`/`nativeGenerateV1`
+//! must share one counter and one clock sequence per process, not one per
+//! binding -- otherwise a caller mixing the FFI and JNI entry points in the
+//! same process would see two independently-incrementing sequences and lose
+//! the monotonicity guarantee.
+
+use crate::{Timestamp, Uuid, UuidError};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Last-used `(milliseconds, rand_a counter)` pair, guarded so concurrent
+/// callers still produce monotonically non-decreasing UUIDs within the same
+/// millisecond.
+static V7_STATE: Mutex<(u64, u16)> = Mutex::new((0, 0));
+
+/// Last-used `(ticks, clock_seq)` pair; the clock sequence is bumped
+/// whenever two calls land in the same or a backwards-moving tick, keeping
+/// generated values unique.
+static V1_STATE: Mutex<Option<(u64, u16)>> = Mutex::new(None);
+
+/// Produces the 16 bytes of a new time-ordered UUID v7, advancing the
+/// shared `V7_STATE` counter to keep same-millisecond calls monotonic.
+pub(crate) fn generate_v7() -> Result<[u8; 16], UuidError> {
+    let now_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| UuidError::EntropyError(format!("System clock is before UNIX epoch: {}", e)))?
+        .as_millis() as u64;
+
+    let entropy = *Uuid::new_v4()?.as_bytes();
+    let counter_seed = u16::from_be_bytes([entropy[0] & 0x0f, entropy[1]]);
+
+    let mut state = V7_STATE.lock().unwrap();
+    let (last_ms, last_counter) = *state;
+
+    let (ms, counter) = if now_ms > last_ms {
+        (now_ms, counter_seed)
+    } else {
+        let next_counter = last_counter.wrapping_add(1);
+        if next_counter > 0x0fff {
+            (last_ms + 1, counter_seed)
+        } else {
+            (last_ms, next_counter)
+        }
+    };
+
+    *state = (ms, counter);
+    drop(state);
+
+    let mut bytes = [0u8; 16];
+    let ts_bytes = ms.to_be_bytes();
+    bytes[0..6].copy_from_slice(&ts_bytes[2..8]);
+
+    bytes[6] = 0x70 | ((counter >> 8) as u8 & 0x0f);
+    bytes[7] = (counter & 0xff) as u8;
+
+    bytes[8] = 0x80 | (entropy[2] & 0x3f);
+    bytes[9..16].copy_from_slice(&entropy[3..10]);
+
+    Ok(bytes)
+}
+
+/// Produces the 16 bytes of a new time-based UUID v1 for the given 6-byte
+/// node id, advancing the shared `V1_STATE` clock sequence.
+pub(crate) fn generate_v1(node_id: [u8; 6]) -> Result<[u8; 16], UuidError> {
+    let timestamp = Timestamp::now()?;
+    let ticks = timestamp.to_ticks();
+
+    let entropy = *Uuid::new_v4()?.as_bytes();
+    let seed_clock_seq = u16::from_be_bytes([entropy[0], entropy[1]]) & 0x3fff;
+
+    let mut state = V1_STATE.lock().unwrap();
+    let clock_seq = match *state {
+        Some((last_ticks, last_seq)) if ticks > last_ticks => last_seq,
+        Some((_, last_seq)) => last_seq.wrapping_add(1) & 0x3fff,
+        None => seed_clock_seq,
+    };
+    *state = Some((ticks, clock_seq));
+    drop(state);
+
+    let (time_low, time_mid, time_hi) = timestamp.to_fields();
+
+    let mut bytes = [0u8; 16];
+    bytes[0..4].copy_from_slice(&time_low.to_be_bytes());
+    bytes[4..6].copy_from_slice(&time_mid.to_be_bytes());
+
+    let time_hi_and_version = (time_hi & 0x0fff) | 0x1000;
+    bytes[6..8].copy_from_slice(&time_hi_and_version.to_be_bytes());
+
+    bytes[8] = ((clock_seq >> 8) as u8 & 0x3f) | 0x80;
+    bytes[9] = (clock_seq & 0xff) as u8;
+    bytes[10..16].copy_from_slice(&node_id);
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_v7_sets_version_and_variant() {
+        let bytes = generate_v7().expect("Should generate UUID");
+        assert_eq!(bytes[6] & 0xf0, 0x70);
+        assert_eq!(bytes[8] & 0xc0, 0x80);
+    }
+
+    #[test]
+    fn test_generate_v7_is_monotonic() {
+        let first = generate_v7().expect("Should generate UUID");
+        let second = generate_v7().expect("Should generate UUID");
+        assert!(first <= second, "Sequential v7 UUIDs should not decrease");
+    }
+
+    #[test]
+    fn test_generate_v1_sets_version_variant_and_node() {
+        let node_id = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+        let bytes = generate_v1(node_id).expect("Should generate UUID");
+        assert_eq!(bytes[6] & 0xf0, 0x10);
+        assert_eq!(bytes[8] & 0xc0, 0x80);
+        assert_eq!(&bytes[10..16], &node_id);
+    }
+}
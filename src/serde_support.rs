@@ -0,0 +1,91 @@
+//! Optional Serde support, enabled via the `serde` feature
+//!
+//! Human-readable formats (JSON, YAML, ...) serialize a [`crate::Uuid`] to
+//! its hyphenated string form and deserialize through
+//! [`crate::Uuid::parse_str`]. Non-human-readable formats (bincode, CBOR,
+//! ...) serialize the raw 16-byte array directly, skipping the cost of
+//! string formatting/parsing. This mirrors `serde::Serializer::is_human_readable`,
+//! the standard way Serde implementations branch on format.
+
+use crate::Uuid;
+use serde::de::{Error as _, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+impl Serialize for Uuid {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            serializer.serialize_bytes(self.as_bytes())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Uuid {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            Uuid::parse_str(&s).map_err(D::Error::custom)
+        } else {
+            deserializer.deserialize_bytes(UuidBytesVisitor)
+        }
+    }
+}
+
+/// Matches the length-prefixed byte sequence written by `serialize_bytes`;
+/// `<[u8; 16]>::deserialize` expects a bare array with no prefix, so the two
+/// sides must agree on a `deserialize_bytes`/visitor pair instead.
+struct UuidBytesVisitor;
+
+impl<'de> Visitor<'de> for UuidBytesVisitor {
+    type Value = Uuid;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("16 bytes")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let bytes: [u8; 16] = v
+            .try_into()
+            .map_err(|_| E::invalid_length(v.len(), &self))?;
+        Ok(Uuid::from_bytes(bytes))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_bytes(&v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_human_readable_roundtrip() {
+        let uuid = Uuid::new_v4().expect("Should generate UUID");
+        let json = serde_json::to_string(&uuid).expect("Should serialize");
+        let decoded: Uuid = serde_json::from_str(&json).expect("Should deserialize");
+        assert_eq!(uuid, decoded);
+    }
+
+    #[test]
+    fn test_non_human_readable_roundtrip() {
+        let uuid = Uuid::new_v4().expect("Should generate UUID");
+        let encoded = bincode::serialize(&uuid).expect("Should serialize");
+        let decoded: Uuid = bincode::deserialize(&encoded).expect("Should deserialize");
+        assert_eq!(uuid, decoded);
+    }
+}
@@ -0,0 +1,89 @@
+//! Gregorian-epoch timestamp handling shared by time-based UUID versions
+//!
+//! RFC 4122/9562 version 1 encodes time as the count of 100-nanosecond
+//! intervals since 1582-10-15 00:00:00 UTC, the start of the Gregorian
+//! calendar. This module isolates that conversion so it is not duplicated
+//! across time-based version implementations (v1 today, v6 if it is ever
+//! added).
+
+use crate::UuidError;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The number of 100ns intervals between the Gregorian epoch (1582-10-15)
+/// and the Unix epoch (1970-01-01)
+const GREGORIAN_TO_UNIX_OFFSET: u64 = 0x01B2_1DD2_1381_4000;
+
+/// A 60-bit count of 100-nanosecond intervals since the Gregorian epoch,
+/// as used by time-based UUID versions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timestamp {
+    ticks: u64,
+}
+
+impl Timestamp {
+    /// Captures the current system time as a Gregorian-epoch timestamp
+    pub fn now() -> Result<Self, UuidError> {
+        let unix_nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| {
+                UuidError::EntropyError(format!("System clock is before UNIX epoch: {}", e))
+            })?
+            .as_nanos() as u64;
+
+        let ticks = (unix_nanos / 100).wrapping_add(GREGORIAN_TO_UNIX_OFFSET);
+        Ok(Timestamp {
+            ticks: ticks & 0x0FFF_FFFF_FFFF_FFFF,
+        })
+    }
+
+    /// Builds a timestamp directly from a 60-bit tick count (any bits above
+    /// bit 59 are discarded)
+    pub fn from_ticks(ticks: u64) -> Self {
+        Timestamp {
+            ticks: ticks & 0x0FFF_FFFF_FFFF_FFFF,
+        }
+    }
+
+    /// Returns the raw 60-bit tick count
+    pub fn to_ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// Splits the timestamp into the `(time_low, time_mid, time_hi)` fields
+    /// used by the v1 byte layout; `time_hi`'s upper 4 bits are left at
+    /// zero for the caller to OR in the version nibble
+    pub fn to_fields(&self) -> (u32, u16, u16) {
+        let time_low = (self.ticks & 0xFFFF_FFFF) as u32;
+        let time_mid = ((self.ticks >> 32) & 0xFFFF) as u16;
+        let time_hi = ((self.ticks >> 48) & 0x0FFF) as u16;
+        (time_low, time_mid, time_hi)
+    }
+
+    /// Reassembles a timestamp from the `(time_low, time_mid,
+    /// time_hi_and_version)` fields, masking out the version nibble
+    pub fn from_fields(time_low: u32, time_mid: u16, time_hi_and_version: u16) -> Self {
+        let time_hi = (time_hi_and_version & 0x0FFF) as u64;
+        let ticks = (time_low as u64) | ((time_mid as u64) << 32) | (time_hi << 48);
+        Timestamp { ticks }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fields_roundtrip() {
+        let ts = Timestamp::from_ticks(0x0ABC_DEF0_1234_5678);
+        let (low, mid, hi) = ts.to_fields();
+        let roundtripped = Timestamp::from_fields(low, mid, hi);
+        assert_eq!(ts, roundtripped);
+    }
+
+    #[test]
+    fn test_now_is_plausible() {
+        let ts = Timestamp::now().expect("Should read system clock");
+        // Any timestamp after the Unix epoch is comfortably past the offset
+        assert!(ts.to_ticks() > GREGORIAN_TO_UNIX_OFFSET);
+    }
+}